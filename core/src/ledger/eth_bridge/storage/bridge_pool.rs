@@ -0,0 +1,407 @@
+//! Functionality for generating Merkle proofs of inclusion over the
+//! bridge pool's pending transfers, so that a relayer can convince the
+//! Ethereum bridge contract that a given subset of transfers belongs
+//! to the current bridge pool root.
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+use crate::types::eth_abi::{Encode, Signature, Token};
+use crate::types::eth_bridge_pool::PendingTransfer;
+use crate::types::keccak::{keccak_hash, KeccakHash};
+use crate::types::vote_extensions::validator_set_update::ValidatorSetArgs;
+
+/// Hashes a pair of sibling nodes, sorting them first so that the
+/// result does not depend on which side of the tree each node came
+/// from.
+fn hash_pair(a: &KeccakHash, b: &KeccakHash) -> KeccakHash {
+    let (left, right) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend_from_slice(left.0.as_ref());
+    bytes.extend_from_slice(right.0.as_ref());
+    keccak_hash(&bytes)
+}
+
+/// A keccak Merkle tree built over the pending [`PendingTransfer`]s of
+/// the bridge pool.
+///
+/// Each leaf is `keccak256(abi_encode(transfer))`, and internal nodes
+/// are `keccak256(left || right)`, with the two children sorted
+/// beforehand for determinism. To keep every layer of the tree evenly
+/// paired, the leaves are padded up to the next power of two with a
+/// deterministic padding leaf; the padding is never returned as part
+/// of a [`BridgePoolMultiProof`].
+#[derive(Debug, Clone)]
+pub struct BridgePoolTree {
+    /// Number of real (non-padding) leaves in the tree.
+    num_transfers: usize,
+    /// All layers of the tree, from the leaves (index `0`) up to a
+    /// single-element layer containing the root.
+    layers: Vec<Vec<KeccakHash>>,
+}
+
+impl BridgePoolTree {
+    /// Builds a new tree over the given `transfers`.
+    pub fn new(transfers: &[PendingTransfer]) -> Self {
+        let mut leaves: Vec<KeccakHash> =
+            transfers.iter().map(|transfer| transfer.keccak256()).collect();
+        let padded_len = leaves.len().max(1).next_power_of_two();
+        leaves.resize(padded_len, Self::padding_leaf());
+
+        let mut layers = vec![leaves];
+        while layers.last().expect("layers is never empty").len() > 1 {
+            let next_layer: Vec<KeccakHash> = layers
+                .last()
+                .expect("layers is never empty")
+                .chunks(2)
+                .map(|pair| hash_pair(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next_layer);
+        }
+
+        Self {
+            num_transfers: transfers.len(),
+            layers,
+        }
+    }
+
+    /// The deterministic leaf hash used to pad the tree up to a power
+    /// of two.
+    fn padding_leaf() -> KeccakHash {
+        keccak_hash(&[])
+    }
+
+    /// Returns the root hash of the tree.
+    pub fn root(&self) -> KeccakHash {
+        self.layers
+            .last()
+            .expect("layers is never empty")[0]
+            .clone()
+    }
+
+    /// Returns the keccak hash of the leaf at the given transfer
+    /// index, if it is in bounds.
+    pub fn leaf(&self, index: usize) -> Option<&KeccakHash> {
+        if index < self.num_transfers {
+            self.layers[0].get(index)
+        } else {
+            None
+        }
+    }
+
+    /// Builds a multiproof of inclusion for the transfers at the
+    /// given `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is empty, or if any of the `indices` is out
+    /// of bounds of the real (non-padding) transfers in the tree.
+    pub fn multiproof(&self, indices: &[usize]) -> BridgePoolMultiProof {
+        assert!(
+            !indices.is_empty(),
+            "Cannot build a multiproof for an empty set of indices"
+        );
+        for &index in indices {
+            assert!(
+                index < self.num_transfers,
+                "Index {index} is out of bounds of the bridge pool tree"
+            );
+        }
+
+        let mut known: Vec<usize> = indices.to_vec();
+        known.sort_unstable();
+        known.dedup();
+
+        let leaves: Vec<KeccakHash> =
+            known.iter().map(|&i| self.layers[0][i].clone()).collect();
+
+        let mut proof = Vec::new();
+        let mut flags = Vec::new();
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            let mut next_known = Vec::with_capacity(known.len());
+            let mut i = 0;
+            while i < known.len() {
+                let index = known[i];
+                let sibling = index ^ 1;
+                if known.get(i + 1) == Some(&sibling) {
+                    // The sibling is also proven, so the verifier will
+                    // already have its hash on the running stack.
+                    flags.push(true);
+                    i += 2;
+                } else {
+                    // The sibling must be supplied as part of the proof.
+                    proof.push(layer[sibling].clone());
+                    flags.push(false);
+                    i += 1;
+                }
+                next_known.push(index / 2);
+            }
+            next_known.dedup();
+            known = next_known;
+        }
+
+        BridgePoolMultiProof {
+            leaves,
+            proof,
+            flags,
+        }
+    }
+}
+
+/// A multiproof of inclusion for a subset of the bridge pool's pending
+/// transfers, in the style of OpenZeppelin's
+/// `MerkleProof.processMultiProof`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct BridgePoolMultiProof {
+    /// Keccak hashes of the proven leaves, sorted by their original
+    /// position in the tree.
+    pub leaves: Vec<KeccakHash>,
+    /// Sibling hashes required to rebuild the root, in the order they
+    /// are consumed.
+    pub proof: Vec<KeccakHash>,
+    /// At each combining step, whether the next hash is popped from
+    /// the running leaf/hash stack (`true`), or from [`Self::proof`]
+    /// (`false`).
+    pub flags: Vec<bool>,
+}
+
+impl BridgePoolMultiProof {
+    /// Reconstructs the Merkle root implied by this multiproof.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the multiproof covers zero leaves, e.g. one built by
+    /// hand rather than via [`BridgePoolTree::multiproof`] (which never
+    /// produces a degenerate, leafless multiproof).
+    pub fn compute_root(&self) -> KeccakHash {
+        let total = self.flags.len();
+        if total == 0 {
+            return self.leaves.first().cloned().expect(
+                "A multiproof must cover at least one leaf to have a root",
+            );
+        }
+
+        let mut hashes: Vec<KeccakHash> = Vec::with_capacity(total);
+        let (mut leaf_pos, mut hash_pos, mut proof_pos) = (0, 0, 0);
+
+        for i in 0..total {
+            let a = if leaf_pos < self.leaves.len() {
+                let hash = self.leaves[leaf_pos].clone();
+                leaf_pos += 1;
+                hash
+            } else {
+                let hash = hashes[hash_pos].clone();
+                hash_pos += 1;
+                hash
+            };
+            let b = if self.flags[i] {
+                if leaf_pos < self.leaves.len() {
+                    let hash = self.leaves[leaf_pos].clone();
+                    leaf_pos += 1;
+                    hash
+                } else {
+                    let hash = hashes[hash_pos].clone();
+                    hash_pos += 1;
+                    hash
+                }
+            } else {
+                let hash = self.proof[proof_pos].clone();
+                proof_pos += 1;
+                hash
+            };
+            hashes.push(hash_pair(&a, &b));
+        }
+
+        hashes[total - 1].clone()
+    }
+}
+
+/// An ABI-encodable relay payload, bundling everything the governance
+/// contract's verify function needs to check a [`BridgePoolMultiProof`]
+/// against a set of validator signatures, ready to be sent to the
+/// Ethereum bridge.
+#[derive(Debug, Clone)]
+pub struct RelayProof<'a> {
+    /// The bridge pool root the proof was generated against.
+    pub root: KeccakHash,
+    /// The multiproof of inclusion for [`Self::transfers`].
+    pub proof: BridgePoolMultiProof,
+    /// The transfers being relayed to Ethereum.
+    pub transfers: &'a [PendingTransfer],
+    /// The validator set that signed off on [`Self::root`].
+    pub validator_set_args: ValidatorSetArgs,
+    /// Validator signatures over [`Self::root`].
+    pub signatures: Vec<Signature>,
+}
+
+impl<'a> Encode<6> for RelayProof<'a> {
+    fn tokenize(&self) -> [Token; 6] {
+        [
+            Token::FixedBytes(self.root.0.to_vec()),
+            Token::Array(
+                self.proof
+                    .proof
+                    .iter()
+                    .map(|hash| Token::FixedBytes(hash.0.to_vec()))
+                    .collect(),
+            ),
+            Token::Array(
+                self.proof
+                    .flags
+                    .iter()
+                    .map(|flag| Token::Bool(*flag))
+                    .collect(),
+            ),
+            Token::Array(
+                self.transfers
+                    .iter()
+                    .map(|transfer| Token::Bytes(transfer.encode().into_inner()))
+                    .collect(),
+            ),
+            Token::Bytes(self.validator_set_args.encode().into_inner()),
+            Token::Array(
+                self.signatures
+                    .iter()
+                    .map(|sig| Token::Bytes(sig.0.to_vec()))
+                    .collect(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::types::address::Address;
+    use crate::types::eth_bridge_pool::{GasFee, TransferToEthereum};
+    use crate::types::ethereum_events::EthAddress;
+    use crate::types::vote_extensions::validator_set_update::ValidatorSetArgs;
+
+    /// Builds a dummy transfer, distinguished by `nonce`-like `amount`.
+    fn dummy_transfer(amount: u64) -> PendingTransfer {
+        PendingTransfer {
+            transfer: TransferToEthereum {
+                asset: EthAddress::from_str(
+                    "0x3949c97925e5Aa13e34ddb18EAbf0B70ABB0C7d4",
+                )
+                .expect("Test failed"),
+                recipient: EthAddress::from_str(
+                    "0x3949c97925e5Aa13e34ddb18EAbf0B70ABB0C7d4",
+                )
+                .expect("Test failed"),
+                sender: Address::decode(
+                    "atest1v4ehgw36xvcyyvejgvenxs34g3zygv3jxqunjd6rxyeyys3sxy6rwvfkx4qnj33hg9qnvse4lsfctw",
+                )
+                .expect("Test failed"),
+                amount: amount.into(),
+            },
+            gas_fee: GasFee {
+                amount: Default::default(),
+                payer: Address::decode(
+                    "atest1v4ehgw36xvcyyvejgvenxs34g3zygv3jxqunjd6rxyeyys3sxy6rwvfkx4qnj33hg9qnvse4lsfctw",
+                )
+                .expect("Test failed"),
+            },
+        }
+    }
+
+    #[test]
+    fn test_multiproof_rebuilds_root_for_single_leaf() {
+        let transfers: Vec<_> = (0..4).map(dummy_transfer).collect();
+        let tree = BridgePoolTree::new(&transfers);
+
+        let multiproof = tree.multiproof(&[2]);
+        assert_eq!(multiproof.compute_root(), tree.root());
+    }
+
+    /// A bridge pool holding exactly one pending transfer never grows
+    /// past a single-leaf tree, so its multiproof carries no siblings
+    /// and `compute_root` must take the `total == 0` early-return
+    /// branch to recover a meaningful root, rather than that branch
+    /// only ever being exercised by manual reasoning.
+    #[test]
+    fn test_multiproof_rebuilds_root_for_single_transfer_pool() {
+        let transfers: Vec<_> = (0..1).map(dummy_transfer).collect();
+        let tree = BridgePoolTree::new(&transfers);
+
+        let multiproof = tree.multiproof(&[0]);
+        assert!(multiproof.proof.is_empty());
+        assert!(multiproof.flags.is_empty());
+        assert_eq!(multiproof.compute_root(), tree.root());
+    }
+
+    #[test]
+    fn test_multiproof_rebuilds_root_for_multiple_leaves() {
+        let transfers: Vec<_> = (0..8).map(dummy_transfer).collect();
+        let tree = BridgePoolTree::new(&transfers);
+
+        let multiproof = tree.multiproof(&[1, 3, 6]);
+        assert_eq!(multiproof.compute_root(), tree.root());
+    }
+
+    #[test]
+    fn test_multiproof_rebuilds_root_for_all_leaves() {
+        let transfers: Vec<_> = (0..4).map(dummy_transfer).collect();
+        let tree = BridgePoolTree::new(&transfers);
+
+        let multiproof = tree.multiproof(&[0, 1, 2, 3]);
+        assert!(multiproof.proof.is_empty());
+        assert_eq!(multiproof.compute_root(), tree.root());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_multiproof_panics_on_out_of_bounds_index() {
+        let transfers: Vec<_> = (0..2).map(dummy_transfer).collect();
+        let tree = BridgePoolTree::new(&transfers);
+        let _ = tree.multiproof(&[5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty set of indices")]
+    fn test_multiproof_panics_on_empty_indices() {
+        let transfers: Vec<_> = (0..2).map(dummy_transfer).collect();
+        let tree = BridgePoolTree::new(&transfers);
+        let _ = tree.multiproof(&[]);
+    }
+
+    #[test]
+    fn test_relay_proof_encodes_without_panicking() {
+        let transfers: Vec<_> = (0..4).map(dummy_transfer).collect();
+        let tree = BridgePoolTree::new(&transfers);
+        let proof = tree.multiproof(&[1, 3]);
+
+        let validator_set_args = ValidatorSetArgs {
+            validators: vec![
+                EthAddress::from_str(
+                    "0x241D37B7Cf5233b3b0b204321420A86e8f7bfdb5",
+                )
+                .expect("Test failed"),
+            ],
+            voting_powers: vec![8828299.into()],
+            epoch: 0.into(),
+        };
+        let signatures = vec![Signature([0u8; 65])];
+
+        let relay_proof = RelayProof {
+            root: tree.root(),
+            proof,
+            transfers: &transfers,
+            validator_set_args,
+            signatures,
+        };
+
+        let tokens = relay_proof.tokenize();
+        assert!(matches!(tokens[0], Token::FixedBytes(_)));
+        assert!(matches!(tokens[1], Token::Array(_)));
+        assert!(matches!(tokens[2], Token::Array(_)));
+        assert!(matches!(tokens[3], Token::Array(_)));
+        assert!(matches!(tokens[4], Token::Bytes(_)));
+        assert!(matches!(tokens[5], Token::Array(_)));
+
+        let encoded = relay_proof.encode().into_inner();
+        assert!(!encoded.is_empty());
+    }
+}
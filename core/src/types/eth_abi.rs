@@ -1,5 +1,5 @@
-//! This module defines encoding methods compatible with Ethereum
-//! smart contracts.
+//! This module defines encoding and decoding methods compatible with
+//! Ethereum smart contracts.
 
 use std::marker::PhantomData;
 
@@ -8,6 +8,7 @@ use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 pub use ethabi::token::Token;
 
 use crate::proto::{Signable, SignableEthMessage};
+use crate::types::ethereum_events::EthAddress;
 use crate::types::keccak::{keccak_hash, KeccakHash};
 
 /// A container for data types that are able to be Ethereum ABI-encoded.
@@ -118,7 +119,418 @@ impl<const N: usize> Encode<N> for AbiEncode<N> {
     }
 }
 
-// TODO: test signatures here once we merge secp keys
+/// A container for data that is able to be Ethereum ABI-decoded,
+/// but has not been decoded into a value of type `T` just yet.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[repr(transparent)]
+pub struct DecodeCell<T: ?Sized> {
+    /// ABI-encoded bytes, waiting to be decoded into `T`.
+    encoded_data: Vec<u8>,
+    /// Indicate we do not own values of type `T`.
+    ///
+    /// Passing `PhantomData<T>` here would trigger the drop checker,
+    /// which is not the desired behavior, since we own an encoded value
+    /// of `T`, not a value of `T` itself.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> AsRef<[u8]> for DecodeCell<T> {
+    fn as_ref(&self) -> &[u8] {
+        &self.encoded_data
+    }
+}
+
+impl<T> DecodeCell<T> {
+    /// Wrap some ABI-encoded bytes, deferring their decoding into `T`.
+    pub fn new(encoded_data: Vec<u8>) -> Self {
+        Self {
+            encoded_data,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decode the wrapped bytes into a value of type `T`.
+    pub fn decode<const N: usize>(&self) -> eyre::Result<T>
+    where
+        T: Decode<N>,
+    {
+        T::decode(&self.encoded_data)
+    }
+
+    /// Return the underlying ABI encoded bytes.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.encoded_data
+    }
+}
+
+/// Contains a method to decode data that was encoded in a format
+/// compatible with Ethereum.
+pub trait Decode<const N: usize>: Sized {
+    /// The ABI [`ethabi::ParamType`] layout of the encoded tokens,
+    /// in the order they should be decoded.
+    fn param_types() -> [ethabi::ParamType; N];
+
+    /// Builds `Self` from a sequence of decoded ABI [`Token`] instances.
+    fn from_tokens(tokens: Vec<Token>) -> eyre::Result<Self>;
+
+    /// Decodes ABI-encoded `data` into a value of `Self`.
+    fn decode(data: &[u8]) -> eyre::Result<Self> {
+        let tokens = ethabi::decode(&Self::param_types(), data)
+            .map_err(|err| eyre::eyre!("Failed to ABI decode data: {err}"))?;
+        Self::from_tokens(tokens)
+    }
+}
+
+impl Decode<4> for crate::types::eth_bridge_pool::TransferToEthereum {
+    fn param_types() -> [ethabi::ParamType; 4] {
+        [
+            ethabi::ParamType::Address,
+            ethabi::ParamType::Address,
+            ethabi::ParamType::String,
+            ethabi::ParamType::Uint(256),
+        ]
+    }
+
+    fn from_tokens(tokens: Vec<Token>) -> eyre::Result<Self> {
+        let mut tokens = tokens.into_iter();
+        let asset = tokens
+            .next()
+            .and_then(Token::into_address)
+            .map(|addr| EthAddress(addr.0))
+            .ok_or_else(|| eyre::eyre!("Expected an address token for `asset`"))?;
+        let recipient = tokens
+            .next()
+            .and_then(Token::into_address)
+            .map(|addr| EthAddress(addr.0))
+            .ok_or_else(|| {
+                eyre::eyre!("Expected an address token for `recipient`")
+            })?;
+        let sender = tokens
+            .next()
+            .and_then(Token::into_string)
+            .ok_or_else(|| eyre::eyre!("Expected a string token for `sender`"))
+            .and_then(|addr| {
+                crate::types::address::Address::decode(&addr)
+                    .map_err(|err| eyre::eyre!("Invalid sender address: {err}"))
+            })?;
+        let amount = tokens
+            .next()
+            .and_then(Token::into_uint)
+            .ok_or_else(|| eyre::eyre!("Expected a uint256 token for `amount`"))?
+            .into();
+        Ok(Self {
+            asset,
+            recipient,
+            sender,
+            amount,
+        })
+    }
+}
+
+impl Decode<1>
+    for crate::types::vote_extensions::validator_set_update::ValidatorSetArgs
+{
+    fn param_types() -> [ethabi::ParamType; 1] {
+        [ethabi::ParamType::Tuple(vec![
+            ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address)),
+            ethabi::ParamType::Array(Box::new(ethabi::ParamType::Uint(256))),
+            ethabi::ParamType::Uint(256),
+        ])]
+    }
+
+    fn from_tokens(tokens: Vec<Token>) -> eyre::Result<Self> {
+        let mut tokens = tokens
+            .into_iter()
+            .next()
+            .and_then(Token::into_tuple)
+            .ok_or_else(|| {
+                eyre::eyre!("Expected a tuple token for `ValidatorSetArgs`")
+            })?
+            .into_iter();
+        let validators = tokens
+            .next()
+            .and_then(Token::into_array)
+            .ok_or_else(|| {
+                eyre::eyre!("Expected an address array token for `validators`")
+            })?
+            .into_iter()
+            .map(|token| {
+                token.into_address().map(|addr| EthAddress(addr.0)).ok_or_else(
+                    || eyre::eyre!("Expected an address token in `validators`"),
+                )
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let voting_powers = tokens
+            .next()
+            .and_then(Token::into_array)
+            .ok_or_else(|| {
+                eyre::eyre!(
+                    "Expected a uint256 array token for `voting_powers`"
+                )
+            })?
+            .into_iter()
+            .map(|token| {
+                token.into_uint().map(Into::into).ok_or_else(|| {
+                    eyre::eyre!("Expected a uint256 token in `voting_powers`")
+                })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let epoch = tokens
+            .next()
+            .and_then(Token::into_uint)
+            .ok_or_else(|| eyre::eyre!("Expected a uint256 token for `epoch`"))?
+            .into();
+        Ok(Self {
+            validators,
+            voting_powers,
+            epoch,
+        })
+    }
+}
+
+/// A container for data types that are able to be Ethereum RLP-encoded.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[repr(transparent)]
+pub struct RlpCell<T: ?Sized> {
+    /// RLP-encoded value of type `T`.
+    rlp_data: Vec<u8>,
+    /// Indicate we do not own values of type `T`.
+    ///
+    /// Passing `PhantomData<T>` here would trigger the drop checker,
+    /// which is not the desired behavior, since we own an encoded value
+    /// of `T`, not a value of `T` itself.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> AsRef<[u8]> for RlpCell<T> {
+    fn as_ref(&self) -> &[u8] {
+        &self.rlp_data
+    }
+}
+
+impl<T: Rlp> RlpCell<T> {
+    /// Return a new RLP encoded value of type `T`.
+    pub fn new(value: &T) -> Self {
+        Self {
+            rlp_data: value.rlp_bytes(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return the underlying RLP encoded value.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.rlp_data
+    }
+}
+
+/// Contains methods to RLP-encode Ethereum transactions and payloads,
+/// as per [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718).
+pub trait Rlp: Sized {
+    /// Appends the fields of `self`, as an RLP list, to the given
+    /// [`rlp::RlpStream`].
+    fn rlp_append(&self, stream: &mut rlp::RlpStream);
+
+    /// The [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718) transaction
+    /// type byte (e.g. `0x01` for access list transactions, `0x02` for
+    /// dynamic fee transactions).
+    ///
+    /// Legacy (type-less) transactions return [`None`], and fall through
+    /// to a plain RLP list, with no type byte prefixed to it.
+    fn tx_type(&self) -> Option<u8> {
+        None
+    }
+
+    /// Returns the RLP encoded value, in a type-safe enclosure.
+    fn rlp(&self) -> RlpCell<Self> {
+        RlpCell::new(self)
+    }
+
+    /// Returns the bytes of the RLP encoding of `self`.
+    ///
+    /// For a typed transaction, this is the single type byte
+    /// concatenated in front of the RLP-encoded list of fields. Legacy
+    /// transactions fall through to a plain RLP list, with no prefix.
+    fn rlp_bytes(&self) -> Vec<u8> {
+        let mut stream = rlp::RlpStream::new();
+        self.rlp_append(&mut stream);
+        let rlp_list = stream.out().to_vec();
+        match self.tx_type() {
+            Some(type_byte) => {
+                let mut bytes = Vec::with_capacity(1 + rlp_list.len());
+                bytes.push(type_byte);
+                bytes.extend(rlp_list);
+                bytes
+            }
+            None => rlp_list,
+        }
+    }
+
+    /// Returns the keccak256 hash of [`Self::rlp_bytes`], i.e.
+    /// `keccak256(type_byte || rlp(fields))`, ready to be signed.
+    fn rlp_signable_hash(&self) -> KeccakHash {
+        keccak_hash(self.rlp_bytes().as_slice())
+    }
+}
+
+/// An [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930) access list
+/// entry: an [`EthAddress`], paired with the 32-byte storage keys
+/// accessed under it.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct AccessListItem {
+    /// The address whose storage is being accessed.
+    pub address: EthAddress,
+    /// The storage keys being accessed under [`Self::address`].
+    pub storage_keys: Vec<KeccakHash>,
+}
+
+impl Rlp for AccessListItem {
+    fn rlp_append(&self, stream: &mut rlp::RlpStream) {
+        stream.begin_list(2);
+        stream.append(&self.address.0.as_ref());
+        stream.begin_list(self.storage_keys.len());
+        for key in &self.storage_keys {
+            stream.append(&key.0.as_ref());
+        }
+    }
+}
+
+/// An [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930) access list,
+/// i.e. a list of [`AccessListItem`] entries.
+pub type AccessList = Vec<AccessListItem>;
+
+/// Appends an [`AccessList`] to an [`rlp::RlpStream`], as a list of
+/// `(address, storage_keys)` pairs.
+pub fn rlp_append_access_list(access_list: &AccessList, stream: &mut rlp::RlpStream) {
+    stream.begin_list(access_list.len());
+    for item in access_list {
+        item.rlp_append(stream);
+    }
+}
+
+/// The secp256k1 curve order, halved, as per
+/// [EIP-2](https://eips.ethereum.org/EIPS/eip-2). Signatures with an
+/// `s` value above this threshold are malleable, and therefore rejected.
+const SECP256K1_HALF_CURVE_ORDER: [u8; 32] = [
+    0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0x5D, 0x57, 0x6E, 0x73, 0x57, 0xA4, 0x50, 0x1D,
+    0xDF, 0xE9, 0x2F, 0x46, 0x68, 0x1B, 0x20, 0xA0,
+];
+
+/// A 65-byte `(r, s, v)` Ethereum ECDSA signature.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[repr(transparent)]
+pub struct Signature(pub [u8; 65]);
+
+/// Errors that can happen when recovering or verifying an Ethereum
+/// ECDSA signature.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum VerifySigError {
+    /// The signature bytes could not be parsed, or do not correspond
+    /// to a valid secp256k1 signature.
+    #[error("Malformed signature: {0}")]
+    MalformedSignature(String),
+    /// The address recovered from the signature does not match the
+    /// address we expected to have signed the message.
+    #[error(
+        "Recovered address {recovered} does not match expected address \
+         {expected}"
+    )]
+    AddressMismatch {
+        /// The address recovered from the signature.
+        recovered: EthAddress,
+        /// The address we expected to recover.
+        expected: EthAddress,
+    },
+}
+
+/// Normalizes an Ethereum `v` recovery id to the `0`/`1` range expected
+/// by secp256k1 ecrecover, accepting both the `0/1` and `27/28`
+/// conventions.
+fn normalize_recovery_id(v: u8) -> Result<u8, VerifySigError> {
+    match v {
+        0 | 1 => Ok(v),
+        27 | 28 => Ok(v - 27),
+        _ => Err(VerifySigError::MalformedSignature(format!(
+            "Invalid recovery id: {v}"
+        ))),
+    }
+}
+
+/// Rejects signatures whose `s` value lies in the upper half of the
+/// secp256k1 curve order, as per
+/// [EIP-2](https://eips.ethereum.org/EIPS/eip-2) low-s malleability
+/// protection.
+fn reject_malleable_s(s: &[u8]) -> Result<(), VerifySigError> {
+    if s > &SECP256K1_HALF_CURVE_ORDER[..] {
+        Err(VerifySigError::MalformedSignature(
+            "Signature `s` value is malleable (upper half of curve order)"
+                .into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Recovers the [`EthAddress`] that produced `signature` over the
+/// `message` digest, using secp256k1 ecrecover.
+pub fn ecrecover(
+    message: &KeccakHash,
+    signature: &Signature,
+) -> Result<EthAddress, VerifySigError> {
+    let (rs, v) = signature.0.split_at(64);
+    let recovery_id = normalize_recovery_id(v[0])?;
+    reject_malleable_s(&rs[32..64])?;
+
+    let recovery_id =
+        libsecp256k1::RecoveryId::parse(recovery_id).map_err(|err| {
+            VerifySigError::MalformedSignature(err.to_string())
+        })?;
+    let sig = libsecp256k1::Signature::parse_standard_slice(rs)
+        .map_err(|err| VerifySigError::MalformedSignature(err.to_string()))?;
+    let msg = libsecp256k1::Message::parse_slice(message.0.as_ref())
+        .map_err(|err| VerifySigError::MalformedSignature(err.to_string()))?;
+
+    let pubkey = libsecp256k1::recover(&msg, &sig, &recovery_id)
+        .map_err(|err| VerifySigError::MalformedSignature(err.to_string()))?;
+    // Skip the leading 0x04 prefix of the uncompressed public key.
+    let hash = keccak_hash(&pubkey.serialize()[1..]);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash.0[12..32]);
+    Ok(EthAddress(address))
+}
+
+/// Verifies that `signature` was produced over the `message` digest by
+/// the holder of `expected`'s private key.
+pub fn verify_signature(
+    message: &KeccakHash,
+    signature: &Signature,
+    expected: &EthAddress,
+) -> Result<(), VerifySigError> {
+    let recovered = ecrecover(message, signature)?;
+    if &recovered == expected {
+        Ok(())
+    } else {
+        Err(VerifySigError::AddressMismatch {
+            recovered,
+            expected: expected.clone(),
+        })
+    }
+}
+
+/// Verifies a batch of `(address, signature)` pairs, all signing over
+/// the same `message` digest, e.g. a set of validator signatures over
+/// a single [`SignableEthMessage`].
+pub fn verify_signatures(
+    message: &KeccakHash,
+    signatures: &[(EthAddress, Signature)],
+) -> Result<(), VerifySigError> {
+    for (expected, signature) in signatures {
+        verify_signature(message, signature, expected)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryInto;
@@ -131,7 +543,6 @@ mod tests {
     use crate::types::eth_bridge_pool::{GasFee, PendingTransfer, TransferToEthereum};
 
     use super::*;
-    use crate::types::ethereum_events::EthAddress;
     use crate::types::vote_extensions::validator_set_update::ValidatorSetArgs;
 
     /// Checks if we get the same result as `abi.encode`, for some given
@@ -233,4 +644,455 @@ mod tests {
             },
         };
     }
+
+    /// Checks that decoding the ABI encoding of a real
+    /// [`TransferToEthereum`] recovers the original value, exercising
+    /// [`Decode`] against the same type the bridge actually relays.
+    #[test]
+    fn test_transfer_to_ethereum_encode_decode_round_trip() {
+        let original = TransferToEthereum {
+            asset: EthAddress::from_str(
+                "0x3949c97925e5Aa13e34ddb18EAbf0B70ABB0C7d4",
+            )
+            .expect("Test failed"),
+            recipient: EthAddress::from_str(
+                "0x3949c97925e5Aa13e34ddb18EAbf0B70ABB0C7d4",
+            )
+            .expect("Test failed"),
+            sender: Address::decode("atest1v4ehgw36xvcyyvejgvenxs34g3zygv3jxqunjd6rxyeyys3sxy6rwvfkx4qnj33hg9qnvse4lsfctw")
+                .expect("Test failed"),
+            amount: 76.into(),
+        };
+        let encoded = original.encode().into_inner();
+        let decoded =
+            TransferToEthereum::decode(&encoded).expect("Test failed");
+        assert_eq!(original, decoded);
+    }
+
+    /// Checks that decoding the ABI encoding of a real
+    /// [`ValidatorSetArgs`] recovers the original value, exercising
+    /// [`Decode`] against the same type used to authenticate bridge
+    /// pool relays.
+    #[test]
+    fn test_validator_set_args_encode_decode_round_trip() {
+        let original = ValidatorSetArgs {
+            validators: vec![
+                EthAddress::from_str(
+                    "0x241D37B7Cf5233b3b0b204321420A86e8f7bfdb5",
+                )
+                .expect("Test failed"),
+            ],
+            voting_powers: vec![8828299.into()],
+            epoch: 0.into(),
+        };
+        let encoded = original.encode().into_inner();
+        let decoded =
+            ValidatorSetArgs::decode(&encoded).expect("Test failed");
+        assert_eq!(original, decoded);
+    }
+
+    /// A minimal type exercising both [`Encode`] and [`Decode`], to check
+    /// that `encode` followed by `decode` is an identity function.
+    #[derive(Debug, PartialEq)]
+    struct RoundTrip {
+        number: U256,
+        name: String,
+    }
+
+    impl Encode<2> for RoundTrip {
+        fn tokenize(&self) -> [Token; 2] {
+            [
+                Token::Uint(self.number),
+                Token::String(self.name.clone()),
+            ]
+        }
+    }
+
+    impl Decode<2> for RoundTrip {
+        fn param_types() -> [ethabi::ParamType; 2] {
+            [ethabi::ParamType::Uint(256), ethabi::ParamType::String]
+        }
+
+        fn from_tokens(tokens: Vec<Token>) -> eyre::Result<Self> {
+            let mut tokens = tokens.into_iter();
+            let number = tokens
+                .next()
+                .and_then(Token::into_uint)
+                .ok_or_else(|| eyre::eyre!("Expected a uint256 token"))?;
+            let name = tokens
+                .next()
+                .and_then(Token::into_string)
+                .ok_or_else(|| eyre::eyre!("Expected a string token"))?;
+            Ok(Self { number, name })
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_is_identity() {
+        let original = RoundTrip {
+            number: U256::from(42u64),
+            name: "test".into(),
+        };
+        let encoded = original.encode().into_inner();
+        let decoded = RoundTrip::decode(&encoded).expect("Test failed");
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn test_decode_cell_round_trip() {
+        let original = RoundTrip {
+            number: U256::from(1337u64),
+            name: "namada".into(),
+        };
+        let cell: DecodeCell<RoundTrip> =
+            DecodeCell::new(original.encode().into_inner());
+        let decoded: RoundTrip = cell.decode().expect("Test failed");
+        assert_eq!(original, decoded);
+    }
+
+    /// A minimal EIP-2930 typed transaction, exercising [`Rlp`].
+    struct AccessListTx {
+        nonce: u64,
+        access_list: AccessList,
+    }
+
+    impl Rlp for AccessListTx {
+        fn rlp_append(&self, stream: &mut rlp::RlpStream) {
+            stream.begin_list(2);
+            stream.append(&self.nonce);
+            rlp_append_access_list(&self.access_list, stream);
+        }
+
+        fn tx_type(&self) -> Option<u8> {
+            Some(0x01)
+        }
+    }
+
+    /// A minimal legacy (type-less) transaction, exercising [`Rlp`].
+    struct LegacyTx {
+        nonce: u64,
+    }
+
+    impl Rlp for LegacyTx {
+        fn rlp_append(&self, stream: &mut rlp::RlpStream) {
+            stream.begin_list(1);
+            stream.append(&self.nonce);
+        }
+    }
+
+    #[test]
+    fn test_typed_tx_rlp_has_type_byte_prefix() {
+        let tx = AccessListTx {
+            nonce: 7,
+            access_list: vec![],
+        };
+        let bytes = tx.rlp_bytes();
+        assert_eq!(bytes[0], 0x01);
+
+        let mut stream = rlp::RlpStream::new();
+        tx.rlp_append(&mut stream);
+        assert_eq!(&bytes[1..], stream.out().as_ref());
+    }
+
+    #[test]
+    fn test_non_empty_access_list_matches_known_rlp_encoding() {
+        let tx = AccessListTx {
+            nonce: 1,
+            access_list: vec![AccessListItem {
+                address: EthAddress([0x11; 20]),
+                storage_keys: vec![KeccakHash([0x22; 32])],
+            }],
+        };
+        let expected = "01f83b01f838f7941111111111111111111111111111111111111111e1a0\
+        2222222222222222222222222222222222222222222222222222222222222222";
+        let expected =
+            HEXLOWER.decode(expected.as_bytes()).expect("Test failed");
+        assert_eq!(tx.rlp_bytes(), expected);
+    }
+
+    #[test]
+    fn test_legacy_tx_rlp_has_no_type_byte_prefix() {
+        let tx = LegacyTx { nonce: 7 };
+        let bytes = tx.rlp_bytes();
+
+        let mut stream = rlp::RlpStream::new();
+        tx.rlp_append(&mut stream);
+        assert_eq!(bytes, stream.out().as_ref());
+    }
+
+    #[test]
+    fn test_rlp_signable_hash_matches_keccak_of_rlp_bytes() {
+        let tx = LegacyTx { nonce: 1 };
+        assert_eq!(
+            tx.rlp_signable_hash(),
+            keccak_hash(tx.rlp_bytes().as_slice())
+        );
+    }
+
+    /// Derives the [`EthAddress`] corresponding to a secp256k1 key pair,
+    /// the same way [`ecrecover`] does.
+    fn eth_address_of(public_key: &libsecp256k1::PublicKey) -> EthAddress {
+        let hash = keccak_hash(&public_key.serialize()[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash.0[12..32]);
+        EthAddress(address)
+    }
+
+    fn sign_test_message(
+        secret_key: &libsecp256k1::SecretKey,
+        message: &KeccakHash,
+    ) -> Signature {
+        let msg = libsecp256k1::Message::parse_slice(message.0.as_ref())
+            .expect("Test failed");
+        let (sig, recovery_id) = libsecp256k1::sign(&msg, secret_key);
+
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(&sig.serialize());
+        bytes[64] = recovery_id.serialize();
+        Signature(bytes)
+    }
+
+    #[test]
+    fn test_ecrecover_round_trip() {
+        let secret_key =
+            libsecp256k1::SecretKey::parse(&[0xAB; 32]).expect("Test failed");
+        let public_key = libsecp256k1::PublicKey::from_secret_key(&secret_key);
+        let expected = eth_address_of(&public_key);
+
+        let message = keccak_hash(b"test message");
+        let signature = sign_test_message(&secret_key, &message);
+
+        let recovered = ecrecover(&message, &signature).expect("Test failed");
+        assert_eq!(recovered, expected);
+        verify_signature(&message, &signature, &expected)
+            .expect("Test failed");
+    }
+
+    /// Checks that [`ecrecover`] derives the well-known Ethereum address
+    /// of private key `1` (i.e. the secp256k1 generator point itself),
+    /// pinning our address-derivation convention against an externally
+    /// known constant, rather than one re-derived with the same logic
+    /// as [`ecrecover`] itself.
+    #[test]
+    fn test_ecrecover_matches_known_private_key_1_address() {
+        let mut secret_key_bytes = [0u8; 32];
+        secret_key_bytes[31] = 1;
+        let secret_key = libsecp256k1::SecretKey::parse(&secret_key_bytes)
+            .expect("Test failed");
+
+        let expected = EthAddress::from_str(
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+        )
+        .expect("Test failed");
+
+        let message = keccak_hash(b"test message");
+        let signature = sign_test_message(&secret_key, &message);
+
+        let recovered = ecrecover(&message, &signature).expect("Test failed");
+        assert_eq!(recovered, expected);
+    }
+
+    #[test]
+    fn test_verify_signature_rejects_wrong_address() {
+        let secret_key =
+            libsecp256k1::SecretKey::parse(&[0xCD; 32]).expect("Test failed");
+        let message = keccak_hash(b"another test message");
+        let signature = sign_test_message(&secret_key, &message);
+
+        let wrong_address = EthAddress([0u8; 20]);
+        let err = verify_signature(&message, &signature, &wrong_address)
+            .expect_err("Test failed");
+        assert!(matches!(err, VerifySigError::AddressMismatch { .. }));
+    }
+
+    #[test]
+    fn test_verify_signatures_batch() {
+        let secret_key_1 =
+            libsecp256k1::SecretKey::parse(&[0x11; 32]).expect("Test failed");
+        let secret_key_2 =
+            libsecp256k1::SecretKey::parse(&[0x22; 32]).expect("Test failed");
+        let message = keccak_hash(b"validator set message");
+
+        let address_1 = eth_address_of(&libsecp256k1::PublicKey::from_secret_key(
+            &secret_key_1,
+        ));
+        let address_2 = eth_address_of(&libsecp256k1::PublicKey::from_secret_key(
+            &secret_key_2,
+        ));
+        let signature_1 = sign_test_message(&secret_key_1, &message);
+        let signature_2 = sign_test_message(&secret_key_2, &message);
+
+        verify_signatures(
+            &message,
+            &[(address_1, signature_1), (address_2, signature_2)],
+        )
+        .expect("Test failed");
+    }
+
+    #[test]
+    fn test_normalize_recovery_id_accepts_both_conventions() {
+        assert_eq!(normalize_recovery_id(0).expect("Test failed"), 0);
+        assert_eq!(normalize_recovery_id(1).expect("Test failed"), 1);
+        assert_eq!(normalize_recovery_id(27).expect("Test failed"), 0);
+        assert_eq!(normalize_recovery_id(28).expect("Test failed"), 1);
+        assert!(normalize_recovery_id(4).is_err());
+    }
+
+    #[test]
+    fn test_reject_malleable_s() {
+        let mut high_s = SECP256K1_HALF_CURVE_ORDER;
+        high_s[31] = high_s[31].wrapping_add(1);
+        assert!(reject_malleable_s(&high_s).is_err());
+        assert!(reject_malleable_s(&SECP256K1_HALF_CURVE_ORDER).is_ok());
+    }
+}
+
+/// A data-driven conformance harness that checks our ABI encoding stays
+/// byte-compatible with Solidity's `abi.encode`, by replaying JSON
+/// fixtures against [`ethabi::encode`] and [`keccak_hash`].
+///
+/// New cases are added as fixture files under `testdata/eth_abi/`,
+/// rather than as new `#[test]` functions.
+#[cfg(test)]
+mod fixtures {
+    use data_encoding::HEXLOWER;
+    use ethabi::ethereum_types::{H160, U256};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Directory containing the conformance fixtures.
+    const FIXTURES_DIR: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/src/types/testdata/eth_abi");
+
+    /// A single JSON-driven ABI-encoding conformance case.
+    #[derive(Debug, Serialize, Deserialize)]
+    struct AbiFixture {
+        /// Human readable description of the case, reported on failure.
+        description: String,
+        /// The tokens to pass to [`ethabi::encode`].
+        tokens: Vec<FixtureToken>,
+        /// The expected `0x`-prefixed ABI encoding hex string.
+        expected_abi_hex: String,
+        /// The expected keccak256 hash of the ABI encoding, as returned
+        /// by [`KeccakHash::to_string`].
+        expected_keccak: String,
+    }
+
+    /// A JSON-friendly stand-in for [`Token`], since `ethabi`'s `Token`
+    /// does not implement `serde`'s (de)serialization traits.
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum FixtureToken {
+        /// A `uint256`, as a base-10 string (to avoid precision loss).
+        Uint { value: String },
+        /// A `0x`-prefixed hex-encoded 20-byte address.
+        Address { value: String },
+        /// A UTF-8 string.
+        String { value: String },
+        /// A `0x`-prefixed hex-encoded byte string.
+        Bytes { value: String },
+        /// A boolean.
+        Bool { value: bool },
+        /// A dynamic array of tokens.
+        Array { value: Vec<FixtureToken> },
+    }
+
+    /// Decodes a `0x`-prefixed (or bare) hex string into bytes.
+    fn decode_hex(value: &str) -> Vec<u8> {
+        HEXLOWER
+            .decode(value.trim_start_matches("0x").to_lowercase().as_bytes())
+            .expect("Malformed fixture: invalid hex string")
+    }
+
+    impl From<&FixtureToken> for Token {
+        fn from(token: &FixtureToken) -> Self {
+            match token {
+                FixtureToken::Uint { value } => Token::Uint(
+                    U256::from_dec_str(value)
+                        .expect("Malformed fixture: invalid uint256"),
+                ),
+                FixtureToken::Address { value } => {
+                    Token::Address(H160::from_slice(&decode_hex(value)))
+                }
+                FixtureToken::String { value } => {
+                    Token::String(value.clone())
+                }
+                FixtureToken::Bytes { value } => {
+                    Token::Bytes(decode_hex(value))
+                }
+                FixtureToken::Bool { value } => Token::Bool(*value),
+                FixtureToken::Array { value } => Token::Array(
+                    value.iter().map(Token::from).collect(),
+                ),
+            }
+        }
+    }
+
+    /// Loads and parses every fixture file under [`FIXTURES_DIR`].
+    fn load_fixtures() -> Vec<(std::path::PathBuf, AbiFixture)> {
+        std::fs::read_dir(FIXTURES_DIR)
+            .expect("Could not read the fixtures directory")
+            .filter_map(|entry| {
+                let path = entry.expect("Test failed").path();
+                (path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+                    .then(|| {
+                        let contents = std::fs::read_to_string(&path)
+                            .expect("Test failed");
+                        let fixture: AbiFixture =
+                            serde_json::from_str(&contents)
+                                .expect("Malformed fixture");
+                        (path, fixture)
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fixtures_are_abi_and_keccak_compatible() {
+        for (_, fixture) in load_fixtures() {
+            let tokens: Vec<Token> =
+                fixture.tokens.iter().map(Token::from).collect();
+            let encoded = ethabi::encode(&tokens);
+
+            let encoded_hex = format!("0x{}", HEXLOWER.encode(&encoded));
+            assert_eq!(
+                encoded_hex, fixture.expected_abi_hex,
+                "ABI encoding mismatch in fixture: {}",
+                fixture.description,
+            );
+
+            let hash = keccak_hash(&encoded);
+            assert_eq!(
+                hash.to_string(),
+                fixture.expected_keccak,
+                "Keccak hash mismatch in fixture: {}",
+                fixture.description,
+            );
+        }
+    }
+
+    /// Regenerates the `expected_abi_hex`/`expected_keccak` fields of
+    /// every fixture in [`FIXTURES_DIR`], from their `tokens`.
+    ///
+    /// Not run as part of the normal test suite; invoke explicitly
+    /// after adding or editing a fixture's `tokens`:
+    /// `cargo test --package namada_core regenerate_fixtures -- --ignored`.
+    #[test]
+    #[ignore]
+    fn regenerate_fixtures() {
+        for (path, mut fixture) in load_fixtures() {
+            let tokens: Vec<Token> =
+                fixture.tokens.iter().map(Token::from).collect();
+            let encoded = ethabi::encode(&tokens);
+
+            fixture.expected_abi_hex =
+                format!("0x{}", HEXLOWER.encode(&encoded));
+            fixture.expected_keccak = keccak_hash(&encoded).to_string();
+
+            let contents = serde_json::to_string_pretty(&fixture)
+                .expect("Test failed");
+            std::fs::write(&path, contents).expect("Test failed");
+        }
+    }
 }